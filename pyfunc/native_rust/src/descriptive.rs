@@ -0,0 +1,140 @@
+use pyo3::prelude::*;
+use pyo3::wrap_pyfunction;
+use rayon::prelude::*;
+
+use crate::errors::{EmptyDataError, InsufficientDataError};
+use crate::{NumericData, PARALLEL_THRESHOLD};
+use std::sync::atomic::Ordering;
+
+/// Single-pass mean/variance accumulator using Welford's online recurrence.
+///
+/// Tracking `mean` and `m2` incrementally avoids the precision loss of the
+/// naive two-pass formula (mean, then sum of squared deviations) for large
+/// or poorly-conditioned inputs, and generalizes naturally to streaming data.
+#[derive(Default, Clone, Copy)]
+pub(crate) struct Welford {
+    pub(crate) n: u64,
+    pub(crate) mean: f64,
+    pub(crate) m2: f64,
+}
+
+impl Welford {
+    pub(crate) fn push(&mut self, x: f64) {
+        self.n += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.n as f64;
+        let delta2 = x - self.mean;
+        self.m2 += delta * delta2;
+    }
+
+    fn from_iter(data: impl IntoIterator<Item = f64>) -> Self {
+        let mut acc = Welford::default();
+        for x in data {
+            acc.push(x);
+        }
+        acc
+    }
+
+    /// Merge two independently-accumulated Welford states, e.g. from two
+    /// halves of a parallel reduction. Standard parallel-variance merge
+    /// formula (Chan et al.).
+    fn combine(a: Welford, b: Welford) -> Welford {
+        if a.n == 0 {
+            return b;
+        }
+        if b.n == 0 {
+            return a;
+        }
+        let n = a.n + b.n;
+        let delta = b.mean - a.mean;
+        let mean = a.mean + delta * b.n as f64 / n as f64;
+        let m2 = a.m2 + b.m2 + delta * delta * (a.n as f64 * b.n as f64) / n as f64;
+        Welford { n, mean, m2 }
+    }
+
+    pub(crate) fn pvariance(&self) -> f64 {
+        self.m2 / self.n as f64
+    }
+
+    pub(crate) fn variance(&self) -> f64 {
+        self.m2 / (self.n - 1) as f64
+    }
+}
+
+/// Accumulate mean/variance over `data`, switching to a rayon-backed
+/// parallel reduction above `PARALLEL_THRESHOLD` elements: each chunk folds
+/// sequentially with `Welford::push`, and chunks are merged with
+/// `Welford::combine`.
+fn welford_over(data: &[f64]) -> Welford {
+    if data.len() > PARALLEL_THRESHOLD.load(Ordering::Relaxed) {
+        data.par_iter()
+            .fold(Welford::default, |mut acc, &x| {
+                acc.push(x);
+                acc
+            })
+            .reduce(Welford::default, Welford::combine)
+    } else {
+        Welford::from_iter(data.iter().copied())
+    }
+}
+
+#[pyfunction]
+fn variance(data: NumericData) -> PyResult<f64> {
+    let data = data.as_slice()?;
+    if data.len() < 2 {
+        return Err(InsufficientDataError::new_err("variance requires at least two data points"));
+    }
+    Ok(welford_over(data).variance())
+}
+
+#[pyfunction]
+fn stdev(data: NumericData) -> PyResult<f64> {
+    Ok(variance(data)?.sqrt())
+}
+
+#[pyfunction]
+fn pvariance(data: NumericData) -> PyResult<f64> {
+    let data = data.as_slice()?;
+    if data.is_empty() {
+        return Err(EmptyDataError::new_err("pvariance() arg is an empty sequence"));
+    }
+    Ok(welford_over(data).pvariance())
+}
+
+#[pyfunction]
+fn pstdev(data: NumericData) -> PyResult<f64> {
+    Ok(pvariance(data)?.sqrt())
+}
+
+pub(crate) fn register(m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(variance, m)?)?;
+    m.add_function(wrap_pyfunction!(stdev, m)?)?;
+    m.add_function(wrap_pyfunction!(pvariance, m)?)?;
+    m.add_function(wrap_pyfunction!(pstdev, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn welford_combine_matches_sequential_accumulation() {
+        let data: Vec<f64> = (1..=1000).map(|x| x as f64 * 0.37).collect();
+        let sequential = Welford::from_iter(data.iter().copied());
+
+        let (left, right) = data.split_at(data.len() / 3);
+        let mut a = Welford::default();
+        for &x in left {
+            a.push(x);
+        }
+        let mut b = Welford::default();
+        for &x in right {
+            b.push(x);
+        }
+        let combined = Welford::combine(a, b);
+
+        assert!((combined.mean - sequential.mean).abs() < 1e-9);
+        assert!((combined.variance() - sequential.variance()).abs() < 1e-6);
+    }
+}
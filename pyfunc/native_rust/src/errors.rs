@@ -0,0 +1,15 @@
+use pyo3::create_exception;
+use pyo3::prelude::*;
+
+create_exception!(native_rust, EmptyDataError, pyo3::exceptions::PyValueError);
+create_exception!(native_rust, InsufficientDataError, pyo3::exceptions::PyValueError);
+create_exception!(native_rust, BufferError, pyo3::exceptions::PyValueError);
+create_exception!(native_rust, InvalidArgumentError, pyo3::exceptions::PyValueError);
+
+pub(crate) fn register(py: Python, m: &PyModule) -> PyResult<()> {
+    m.add("EmptyDataError", py.get_type::<EmptyDataError>())?;
+    m.add("InsufficientDataError", py.get_type::<InsufficientDataError>())?;
+    m.add("BufferError", py.get_type::<BufferError>())?;
+    m.add("InvalidArgumentError", py.get_type::<InvalidArgumentError>())?;
+    Ok(())
+}
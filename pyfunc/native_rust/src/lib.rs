@@ -1,41 +1,94 @@
+use numpy::PyReadonlyArray1;
 use pyo3::prelude::*;
 use pyo3::wrap_pyfunction;
+use rayon::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
-#[pyfunction]
-fn median(mut data: Vec<f64>) -> PyResult<f64> {
-    if data.is_empty() {
-        return Err(pyo3::exceptions::PyValueError::new_err("median() arg is an empty sequence"));
+mod descriptive;
+mod errors;
+mod quantiles;
+mod streaming;
+
+/// Input accepted across the numeric functions: either a Python list
+/// (materialized into an owned `Vec<f64>`) or a NumPy `ndarray`, borrowed
+/// zero-copy through the buffer protocol. This lets callers passing large
+/// arrays skip the per-element `PyObject` conversion that dominates runtime
+/// for list input.
+pub(crate) enum NumericData<'py> {
+    Owned(Vec<f64>),
+    Borrowed(PyReadonlyArray1<'py, f64>),
+}
+
+impl<'py> FromPyObject<'py> for NumericData<'py> {
+    fn extract(obj: &'py PyAny) -> PyResult<Self> {
+        if let Ok(array) = obj.extract::<PyReadonlyArray1<f64>>() {
+            Ok(NumericData::Borrowed(array))
+        } else {
+            Ok(NumericData::Owned(obj.extract::<Vec<f64>>()?))
+        }
+    }
+}
+
+impl<'py> NumericData<'py> {
+    /// Array input must be contiguous to hand out a borrowed slice; map that
+    /// failure the same way everywhere so it's catchable by type regardless
+    /// of which function the caller went through.
+    fn not_contiguous(e: numpy::NotContiguousError) -> PyErr {
+        errors::BufferError::new_err(e.to_string())
     }
 
-    data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    pub(crate) fn as_slice(&self) -> PyResult<&[f64]> {
+        match self {
+            NumericData::Owned(v) => Ok(v.as_slice()),
+            NumericData::Borrowed(a) => a.as_slice().map_err(Self::not_contiguous),
+        }
+    }
 
-    let mid = data.len() / 2;
-    if data.len() % 2 == 0 {
-        Ok((data[mid - 1] + data[mid]) / 2.0)
-    } else {
-        Ok(data[mid])
+    /// Consume into an owned, sortable buffer. For list input this is free
+    /// (the `Vec` is already owned); for array input it's a single bulk copy
+    /// out of the borrowed slice, still far cheaper than per-element unboxing.
+    pub(crate) fn into_vec(self) -> PyResult<Vec<f64>> {
+        match self {
+            NumericData::Owned(v) => Ok(v),
+            NumericData::Borrowed(a) => Ok(a.as_slice().map_err(Self::not_contiguous)?.to_vec()),
+        }
     }
 }
 
+/// Below this element count, the sequential path is used for sorting and
+/// reduction: spinning up rayon's thread pool costs more than it saves for
+/// small inputs. Tunable at runtime via `set_parallel_threshold` for
+/// workloads that want a different crossover point.
+pub(crate) static PARALLEL_THRESHOLD: AtomicUsize = AtomicUsize::new(50_000);
+
 #[pyfunction]
-fn stdev(data: Vec<f64>) -> PyResult<f64> {
-    let n = data.len();
-    if n < 2 {
-        return Err(pyo3::exceptions::PyValueError::new_err("stdev() requires at least two data points"));
-    }
+fn set_parallel_threshold(n: usize) {
+    PARALLEL_THRESHOLD.store(n, Ordering::Relaxed);
+}
 
-    let mean = data.iter().sum::<f64>() / n as f64;
-    let variance = data.iter().map(|value| {
-        let diff = mean - value;
-        diff * diff
-    }).sum::<f64>() / n as f64;
+#[pyfunction]
+fn get_parallel_threshold() -> usize {
+    PARALLEL_THRESHOLD.load(Ordering::Relaxed)
+}
 
-    Ok(variance.sqrt())
+pub(crate) fn sort_for_stats(data: &mut [f64]) {
+    if data.len() > PARALLEL_THRESHOLD.load(Ordering::Relaxed) {
+        data.par_sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+    } else {
+        data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    }
 }
 
 #[pymodule]
-fn native_rust(_py: Python, m: &PyModule) -> PyResult<()> {
-    m.add_function(wrap_pyfunction!(median, m)?)?;
-    m.add_function(wrap_pyfunction!(stdev, m)?)?;
+fn native_rust(py: Python, m: &PyModule) -> PyResult<()> {
+    m.add("__version__", env!("CARGO_PKG_VERSION"))?;
+
+    errors::register(py, m)?;
+    descriptive::register(m)?;
+    quantiles::register(m)?;
+    streaming::register(m)?;
+
+    m.add_function(wrap_pyfunction!(set_parallel_threshold, m)?)?;
+    m.add_function(wrap_pyfunction!(get_parallel_threshold, m)?)?;
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,116 @@
+use pyo3::prelude::*;
+use pyo3::wrap_pyfunction;
+
+use crate::errors::{EmptyDataError, InvalidArgumentError};
+use crate::{sort_for_stats, NumericData};
+
+#[pyfunction]
+fn median(data: NumericData) -> PyResult<f64> {
+    let mut data = data.into_vec()?;
+    if data.is_empty() {
+        return Err(EmptyDataError::new_err("median() arg is an empty sequence"));
+    }
+    sort_for_stats(&mut data);
+    quantile_from_sorted(&data, 0.5, "linear")
+}
+
+/// One or more quantile fractions, accepted as either a single float or a
+/// list so `quantile()` can return a scalar or a list to match.
+enum PValues {
+    Single(f64),
+    Many(Vec<f64>),
+}
+
+impl<'py> FromPyObject<'py> for PValues {
+    fn extract(obj: &'py PyAny) -> PyResult<Self> {
+        if let Ok(p) = obj.extract::<f64>() {
+            Ok(PValues::Single(p))
+        } else {
+            Ok(PValues::Many(obj.extract::<Vec<f64>>()?))
+        }
+    }
+}
+
+/// Compute quantile `p` (`p` in `[0, 1]`) from data that is already sorted,
+/// using the requested interpolation method.
+fn quantile_from_sorted(sorted: &[f64], p: f64, method: &str) -> PyResult<f64> {
+    if !(0.0..=1.0).contains(&p) {
+        return Err(InvalidArgumentError::new_err("p must be between 0 and 1"));
+    }
+
+    let h = p * (sorted.len() - 1) as f64;
+    let lo = h.floor() as usize;
+    let hi = h.ceil() as usize;
+
+    match method {
+        "linear" => Ok(sorted[lo] + (sorted[hi] - sorted[lo]) * (h - lo as f64)),
+        "lower" => Ok(sorted[lo]),
+        "higher" => Ok(sorted[hi]),
+        // NumPy's `nearest` breaks ties round-half-to-even, not away from
+        // zero -- e.g. 6 elements at p=0.5 gives h=2.5, and NumPy picks
+        // index 2, not 3.
+        "nearest" => Ok(sorted[h.round_ties_even() as usize]),
+        "midpoint" => Ok((sorted[lo] + sorted[hi]) / 2.0),
+        other => Err(InvalidArgumentError::new_err(format!(
+            "unknown interpolation method: {other:?}"
+        ))),
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (data, p, method = "linear"))]
+fn quantile(py: Python, data: NumericData, p: PValues, method: &str) -> PyResult<PyObject> {
+    let mut sorted = data.into_vec()?;
+    if sorted.is_empty() {
+        return Err(EmptyDataError::new_err("quantile() arg is an empty sequence"));
+    }
+    sort_for_stats(&mut sorted);
+
+    match p {
+        PValues::Single(p) => Ok(quantile_from_sorted(&sorted, p, method)?.into_py(py)),
+        PValues::Many(ps) => {
+            let values = ps
+                .into_iter()
+                .map(|p| quantile_from_sorted(&sorted, p, method))
+                .collect::<PyResult<Vec<f64>>>()?;
+            Ok(values.into_py(py))
+        }
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (data, p, method = "linear"))]
+fn percentile(py: Python, data: NumericData, p: PValues, method: &str) -> PyResult<PyObject> {
+    let to_fraction = |p: f64| -> PyResult<f64> {
+        if !(0.0..=100.0).contains(&p) {
+            return Err(InvalidArgumentError::new_err("p must be between 0 and 100"));
+        }
+        Ok(p / 100.0)
+    };
+
+    let p = match p {
+        PValues::Single(p) => PValues::Single(to_fraction(p)?),
+        PValues::Many(ps) => PValues::Many(ps.into_iter().map(to_fraction).collect::<PyResult<Vec<f64>>>()?),
+    };
+    quantile(py, data, p, method)
+}
+
+pub(crate) fn register(m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(median, m)?)?;
+    m.add_function(wrap_pyfunction!(quantile, m)?)?;
+    m.add_function(wrap_pyfunction!(percentile, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nearest_breaks_ties_round_half_to_even_like_numpy() {
+        let sorted = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+        // h = 0.5 * 5 = 2.5, tied between indices 2 and 3; NumPy rounds to
+        // the even index (2), not away from zero (3).
+        assert_eq!(quantile_from_sorted(&sorted, 0.5, "nearest").unwrap(), 2.0);
+    }
+}
@@ -0,0 +1,289 @@
+// pyo3 0.20's #[pymethods] expansion trips rustc's non_local_definitions
+// lint on recent toolchains; the lint fires inside the macro expansion
+// itself, so it can only be silenced module-wide, not on the impl block.
+#![allow(non_local_definitions)]
+
+use pyo3::prelude::*;
+
+use crate::descriptive::Welford;
+use crate::errors::{InsufficientDataError, InvalidArgumentError};
+
+/// Approximate streaming quantile estimator using the P² (P-square) algorithm.
+///
+/// Maintains five markers covering the target quantile `p` and adjusts their
+/// heights and positions on every sample, so the estimate converges without
+/// ever storing the full data set. See Jain & Chlamtac, "The P² Algorithm for
+/// Dynamic Calculation of Quantiles and Histograms Without Storing
+/// Observations" (1985).
+#[derive(Clone)]
+struct P2Estimator {
+    p: f64,
+    // Buffered samples until we have five, after which `markers` takes over.
+    buffer: Vec<f64>,
+    markers: Option<P2Markers>,
+}
+
+#[derive(Clone)]
+struct P2Markers {
+    q: [f64; 5],
+    n: [f64; 5],
+    np: [f64; 5],
+    dn: [f64; 5],
+}
+
+impl P2Estimator {
+    fn new(p: f64) -> Self {
+        P2Estimator { p, buffer: Vec::with_capacity(5), markers: None }
+    }
+
+    fn push(&mut self, x: f64) {
+        if let Some(m) = &mut self.markers {
+            m.push(x);
+        } else {
+            self.buffer.push(x);
+            if self.buffer.len() == 5 {
+                self.buffer.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                self.markers = Some(P2Markers::new(self.p, &self.buffer));
+            }
+        }
+    }
+
+    fn value(&self) -> Option<f64> {
+        if let Some(m) = &self.markers {
+            Some(m.q[2])
+        } else if self.buffer.is_empty() {
+            None
+        } else {
+            // Fewer than five samples: exact linear-interpolated quantile `p`
+            // of the buffer (for p = 0.5 this is the ordinary median).
+            let mut sorted = self.buffer.clone();
+            sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            let h = self.p * (sorted.len() - 1) as f64;
+            let lo = h.floor() as usize;
+            let hi = h.ceil() as usize;
+            Some(sorted[lo] + (sorted[hi] - sorted[lo]) * (h - lo as f64))
+        }
+    }
+}
+
+impl P2Markers {
+    fn new(p: f64, sorted5: &[f64]) -> Self {
+        let mut q = [0.0; 5];
+        q.copy_from_slice(sorted5);
+        P2Markers {
+            q,
+            n: [1.0, 2.0, 3.0, 4.0, 5.0],
+            np: [1.0, 1.0 + 2.0 * p, 1.0 + 4.0 * p, 3.0 + 2.0 * p, 5.0],
+            dn: [0.0, p / 2.0, p, (1.0 + p) / 2.0, 1.0],
+        }
+    }
+
+    fn push(&mut self, x: f64) {
+        if x < self.q[0] {
+            self.q[0] = x;
+        } else if x >= self.q[4] {
+            self.q[4] = x;
+        }
+
+        let k = if x < self.q[1] {
+            0
+        } else if x < self.q[2] {
+            1
+        } else if x < self.q[3] {
+            2
+        } else {
+            3
+        };
+
+        for i in (k + 1)..5 {
+            self.n[i] += 1.0;
+        }
+        for i in 0..5 {
+            self.np[i] += self.dn[i];
+        }
+
+        for i in 1..4 {
+            let d = self.np[i] - self.n[i];
+            if (d >= 1.0 && self.n[i + 1] - self.n[i] > 1.0)
+                || (d <= -1.0 && self.n[i - 1] - self.n[i] < -1.0)
+            {
+                let s = d.signum();
+                let parabolic = self.q[i]
+                    + s / (self.n[i + 1] - self.n[i - 1])
+                        * ((self.n[i] - self.n[i - 1] + s) * (self.q[i + 1] - self.q[i])
+                            / (self.n[i + 1] - self.n[i])
+                            + (self.n[i + 1] - self.n[i] - s) * (self.q[i] - self.q[i - 1])
+                                / (self.n[i] - self.n[i - 1]));
+
+                self.q[i] = if self.q[i - 1] < parabolic && parabolic < self.q[i + 1] {
+                    parabolic
+                } else if s > 0.0 {
+                    self.q[i] + (self.q[i + 1] - self.q[i]) / (self.n[i + 1] - self.n[i])
+                } else {
+                    self.q[i] - (self.q[i - 1] - self.q[i]) / (self.n[i - 1] - self.n[i])
+                };
+                self.n[i] += s;
+            }
+        }
+    }
+}
+
+/// Stateful accumulator for streaming statistics.
+///
+/// Updates mean/variance (Welford) and an approximate median (P²) in O(1)
+/// per sample, without retaining the full sequence of observations --
+/// intended for telemetry-style workloads too large to hold in memory.
+///
+/// Besides the hardcoded median, quantiles to track must be named up front
+/// via the `quantiles` constructor argument: a P² estimator only converges
+/// over the samples it has actually seen, so one created on first query
+/// would silently miss everything pushed before that point. `quantile(p)`
+/// rejects any `p` that wasn't registered at construction time.
+#[pyclass]
+struct RunningStats {
+    welford: Welford,
+    min: Option<f64>,
+    max: Option<f64>,
+    median_estimator: P2Estimator,
+    quantile_estimators: std::collections::HashMap<u64, P2Estimator>,
+}
+
+#[pymethods]
+impl RunningStats {
+    #[new]
+    #[pyo3(signature = (quantiles = Vec::new()))]
+    fn new(quantiles: Vec<f64>) -> PyResult<Self> {
+        let mut quantile_estimators = std::collections::HashMap::new();
+        for p in quantiles {
+            if !(0.0..=1.0).contains(&p) {
+                return Err(InvalidArgumentError::new_err("p must be between 0 and 1"));
+            }
+            quantile_estimators.insert(p.to_bits(), P2Estimator::new(p));
+        }
+        Ok(RunningStats {
+            welford: Welford::default(),
+            min: None,
+            max: None,
+            median_estimator: P2Estimator::new(0.5),
+            quantile_estimators,
+        })
+    }
+
+    fn push(&mut self, x: f64) {
+        self.welford.push(x);
+        self.min = Some(self.min.map_or(x, |m| m.min(x)));
+        self.max = Some(self.max.map_or(x, |m| m.max(x)));
+        self.median_estimator.push(x);
+        for estimator in self.quantile_estimators.values_mut() {
+            estimator.push(x);
+        }
+    }
+
+    /// Accepts any Python iterable and pushes its items one at a time,
+    /// rather than `Vec<f64>` -- materializing the whole argument up front
+    /// would defeat the point of a streaming accumulator for callers passing
+    /// a generator over data too large to hold in memory.
+    fn extend(&mut self, values: &PyAny) -> PyResult<()> {
+        for item in values.iter()? {
+            self.push(item?.extract::<f64>()?);
+        }
+        Ok(())
+    }
+
+    fn quantile(&self, p: f64) -> PyResult<Option<f64>> {
+        if !(0.0..=1.0).contains(&p) {
+            return Err(InvalidArgumentError::new_err("p must be between 0 and 1"));
+        }
+        if p == 0.5 {
+            return Ok(self.median_estimator.value());
+        }
+        match self.quantile_estimators.get(&p.to_bits()) {
+            Some(estimator) => Ok(estimator.value()),
+            None => Err(InvalidArgumentError::new_err(
+                "p was not tracked from construction -- pass quantiles=[p, ...] to RunningStats()",
+            )),
+        }
+    }
+
+    #[getter]
+    fn count(&self) -> u64 {
+        self.welford.n
+    }
+
+    #[getter]
+    fn mean(&self) -> Option<f64> {
+        if self.welford.n == 0 { None } else { Some(self.welford.mean) }
+    }
+
+    #[getter]
+    fn variance(&self) -> PyResult<f64> {
+        if self.welford.n < 2 {
+            return Err(InsufficientDataError::new_err("variance requires at least two data points"));
+        }
+        Ok(self.welford.variance())
+    }
+
+    #[getter]
+    fn stdev(&self) -> PyResult<f64> {
+        Ok(self.variance()?.sqrt())
+    }
+
+    #[getter]
+    fn min(&self) -> Option<f64> {
+        self.min
+    }
+
+    #[getter]
+    fn max(&self) -> Option<f64> {
+        self.max
+    }
+
+    #[getter]
+    fn median(&self) -> Option<f64> {
+        self.median_estimator.value()
+    }
+}
+
+pub(crate) fn register(m: &PyModule) -> PyResult<()> {
+    m.add_class::<RunningStats>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reference_quantile(sorted: &[f64], p: f64) -> f64 {
+        let h = p * (sorted.len() - 1) as f64;
+        let lo = h.floor() as usize;
+        let hi = h.ceil() as usize;
+        sorted[lo] + (sorted[hi] - sorted[lo]) * (h - lo as f64)
+    }
+
+    #[test]
+    fn median_and_tracked_quantile_match_reference_on_uniform_data() {
+        let data: Vec<f64> = (1..=10_000).map(|x| x as f64).collect();
+        let mut stats = RunningStats::new(vec![0.9]).unwrap();
+        Python::with_gil(|py| stats.extend(data.clone().into_py(py).into_ref(py))).unwrap();
+
+        let mut sorted = data;
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let expected_median = reference_quantile(&sorted, 0.5);
+        let expected_p90 = reference_quantile(&sorted, 0.9);
+
+        let median = stats.median().unwrap();
+        let p90 = stats.quantile(0.9).unwrap().unwrap();
+
+        assert!((median - expected_median).abs() / expected_median < 0.01);
+        assert!((p90 - expected_p90).abs() / expected_p90 < 0.01);
+    }
+
+    #[test]
+    fn quantile_rejects_p_not_tracked_from_construction() {
+        let mut stats = RunningStats::new(vec![]).unwrap();
+        let data: Vec<f64> = (1..=100).map(|x| x as f64).collect();
+        Python::with_gil(|py| stats.extend(data.into_py(py).into_ref(py))).unwrap();
+
+        assert!(stats.quantile(0.9).is_err());
+    }
+}